@@ -0,0 +1,65 @@
+//! A type-keyed registry for app-global singletons
+//!
+//! `register_app!` used to stash the model in a named crate-level static, which
+//! offered no guard against reaching for the wrong model type. Instead we key
+//! each registered model by its concrete [`TypeId`], so looking one up is tied
+//! to its type: asking for the wrong type simply yields `None`, and distinct
+//! model types never share storage.
+//!
+//! This only removes the *model* static; the rest of `register_app!`'s output
+//! (the `RmpModel` object, the callback traits, and `setup_scaffolding!()`) is
+//! still fixed-name and crate-level, so the macro is invoked at most once per
+//! crate.
+
+use once_cell::sync::Lazy;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// The lock the registry wraps each model in.
+///
+/// Synchronous by default; with the `async` feature the model is guarded by an
+/// async-aware mutex instead, matching the lock used by the exported async
+/// `action` method.
+#[cfg(not(feature = "async"))]
+pub type GlobalLock<T> = std::sync::RwLock<T>;
+
+#[cfg(feature = "async")]
+pub type GlobalLock<T> = futures::lock::Mutex<T>;
+
+/// Marker trait for types that can live in the global registry.
+///
+/// It is implemented for a model by `register_app!`; app code never needs to
+/// implement it by hand.
+pub trait Global: Any + Send + Sync {}
+
+// Entries are boxed and leaked so the registry can hand out `'static`
+// references; a process only ever registers a handful of models, each for the
+// lifetime of the program, so this never grows unbounded.
+static REGISTRY: Lazy<Mutex<HashMap<TypeId, &'static (dyn Any + Send + Sync)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Register `model` under its concrete type, returning a reference to the stored
+/// lock.
+///
+/// If a model of this type was already registered the existing one is returned
+/// unchanged and `model` is dropped, so callers can treat this as a
+/// get-or-insert.
+pub fn set_global<T: Global>(model: T) -> &'static GlobalLock<T> {
+    let mut registry = REGISTRY.lock().expect("global registry poisoned");
+    let stored: &'static (dyn Any + Send + Sync) =
+        *registry.entry(TypeId::of::<T>()).or_insert_with(|| {
+            let leaked: &'static GlobalLock<T> = Box::leak(Box::new(GlobalLock::new(model)));
+            leaked
+        });
+    stored
+        .downcast_ref::<GlobalLock<T>>()
+        .expect("global registered under a mismatched type")
+}
+
+/// Look up the model registered for type `T`, if any.
+pub fn get_global<T: Global>() -> Option<&'static GlobalLock<T>> {
+    let registry = REGISTRY.lock().expect("global registry poisoned");
+    let stored: &'static (dyn Any + Send + Sync) = *registry.get(&TypeId::of::<T>())?;
+    stored.downcast_ref::<GlobalLock<T>>()
+}
@@ -7,12 +7,14 @@
 pub use uniffi;
 
 // Internal modules
+mod global;
 mod macros;
 #[cfg(test)]
 mod tests;
 mod utils;
 
 // Public exports
+pub use global::{get_global, set_global, Global, GlobalLock};
 pub use utils::{create_app_builder, create_model_update_channel, listen_for_model_updates};
 
 // Note: Macros exported with #[macro_export] are automatically available at the crate root
@@ -22,8 +24,17 @@ pub use utils::{create_app_builder, create_model_update_channel, listen_for_mode
 pub mod traits;
 
 // Re-export key traits for convenience
-pub use traits::{AppBuilder, BuildableApp, RmpAppModel, RmpViewModel};
+pub use traits::{
+    AppBuilder, BuildableApp, NoActionError, NoSubscription, RmpAppModel, RmpViewModel,
+    SubscriptionKey,
+};
 
 // Re-export frequently used types to make it easier for app developers
 pub use crossbeam;
 pub use once_cell;
+
+// Re-exported so the async variant generated by `register_app!` can block on
+// the async-aware lock (`futures::executor::block_on`) without the user crate
+// depending on `futures` directly.
+#[cfg(feature = "async")]
+pub use futures;
@@ -10,25 +10,121 @@
 /// ```ignore
 /// use rust_multiplatform::register_app;
 ///
-/// // First define your model, view model, action, and update types
+/// // First define your model, view model, action, update, and effect types
 /// struct Model { /* ... */ }
 /// struct ViewModel(/* ... */);
 /// enum Action { /* ... */ }
 /// enum ModelUpdate { /* ... */ }
+/// enum Effect { /* ... */ }
+/// struct View { /* ... */ }
+/// enum ActionError { /* ... */ }
+/// enum Subscription { /* ... */ }
 ///
 /// // Then register your app
-/// register_app!(Model, ViewModel, Action, ModelUpdate);
+/// register_app!(
+///     Model, ViewModel, Action, ModelUpdate, Effect, View, ActionError, Subscription
+/// );
+///
+/// // Infallible models may omit the action error; it defaults to `NoActionError`:
+/// register_app!(Model, ViewModel, Action, ModelUpdate, Effect, View, Subscription);
 /// ```
 #[macro_export]
 macro_rules! register_app {
-    ($Model:ident, $ViewModel:ident, $Action:ident, $ModelUpdate:ident) => {
-        // 1. Global static definitions for model and view model
-        static GLOBAL_MODEL: $crate::once_cell::sync::OnceCell<std::sync::RwLock<$Model>> =
-            $crate::once_cell::sync::OnceCell::new();
+    // Convenience form for infallible models: omit the action error. Because a
+    // library-defined type cannot derive `uniffi::Error` (the framework crate
+    // has no scaffolding of its own), the default FFI error is generated *here*,
+    // in the consumer crate where `setup_scaffolding!()` runs. It is uninhabited,
+    // so the `From<NoActionError>` bridge can only ever be a no-op.
+    ($Model:ident, $ViewModel:ident, $Action:ident, $ModelUpdate:ident, $Effect:ident, $View:ident, $Subscription:ident) => {
+        #[derive(Debug, ::uniffi::Error)]
+        pub enum RmpNoActionError {}
+
+        impl ::core::convert::From<$crate::traits::NoActionError> for RmpNoActionError {
+            fn from(never: $crate::traits::NoActionError) -> Self {
+                match never {}
+            }
+        }
+
+        $crate::register_app!(
+            @build
+            $Model,
+            $ViewModel,
+            $Action,
+            $ModelUpdate,
+            $Effect,
+            $View,
+            $crate::traits::NoActionError,
+            RmpNoActionError,
+            $Subscription
+        );
+    };
+
+    // Fallible form: the model's error type is both the `RmpAppModel` parameter
+    // and the thrown FFI error, so the two coincide.
+    ($Model:ident, $ViewModel:ident, $Action:ident, $ModelUpdate:ident, $Effect:ident, $View:ident, $ActionError:ty, $Subscription:ident) => {
+        $crate::register_app!(
+            @build
+            $Model,
+            $ViewModel,
+            $Action,
+            $ModelUpdate,
+            $Effect,
+            $View,
+            $ActionError,
+            $ActionError,
+            $Subscription
+        );
+    };
+
+    // Internal expansion. `$TraitErr` is the model's `RmpAppModel` error
+    // parameter (used to resolve the trait impl); `$FfiErr` is the
+    // uniffi-exportable error thrown across the FFI. They coincide for fallible
+    // models and differ only for the infallible default.
+    (@build $Model:ident, $ViewModel:ident, $Action:ident, $ModelUpdate:ident, $Effect:ident, $View:ident, $TraitErr:ty, $FfiErr:ty, $Subscription:ident) => {
+        // 1. Global singletons.
+        //
+        // The model is stored in the framework's type-keyed registry rather than
+        // a named static, so a lookup is checked against its concrete type (a
+        // wrong-type query yields `None`). The registry wraps it in a synchronous
+        // `RwLock` by default, or an async-aware mutex with the `async` feature,
+        // whose guard can be held across the `.await` in `action_async`. Model
+        // updates are still delivered over the crossbeam channel either way.
+        //
+        // Note: the other generated items below (the `RmpModel` object, the
+        // callback traits, and `setup_scaffolding!()`) are still fixed-name,
+        // crate-level singletons, so `register_app!` is invoked at most once per
+        // crate.
+        impl $crate::Global for $Model {}
 
         static GLOBAL_VIEW_MODEL: $crate::once_cell::sync::OnceCell<$ViewModel> =
             $crate::once_cell::sync::OnceCell::new();
 
+        // The shell-provided sink for effects requested by the core, plus a
+        // monotonic counter used to correlate each request with its eventual
+        // `resolve_effect` call.
+        static GLOBAL_EFFECT_HANDLER: $crate::once_cell::sync::OnceCell<
+            Box<dyn RmpEffectHandler>,
+        > = $crate::once_cell::sync::OnceCell::new();
+
+        static EFFECT_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+        // The ids of effects handed to the shell but not yet resolved.
+        // `resolve_effect` consults this so a response quoting an unknown or
+        // already-resolved id is a no-op rather than a spurious re-dispatch.
+        static GLOBAL_PENDING_EFFECTS: $crate::once_cell::sync::OnceCell<
+            std::sync::Mutex<std::collections::HashSet<u64>>,
+        > = $crate::once_cell::sync::OnceCell::new();
+
+        // The shell-provided sink for subscription changes, plus the set of
+        // subscription keys currently running, used to diff after each action.
+        static GLOBAL_SUBSCRIPTION_SINK: $crate::once_cell::sync::OnceCell<
+            Box<dyn RmpSubscriptionSink>,
+        > = $crate::once_cell::sync::OnceCell::new();
+
+        static GLOBAL_ACTIVE_SUBSCRIPTIONS: $crate::once_cell::sync::OnceCell<
+            std::sync::Mutex<std::collections::HashSet<String>>,
+        > = $crate::once_cell::sync::OnceCell::new();
+
         // 2. Define the RmpViewModel trait first
         // In regular usage, mark with uniffi callback_interface
         // #[cfg(not(test))]
@@ -37,6 +133,25 @@ macro_rules! register_app {
             fn model_update(&self, model_update: $ModelUpdate);
         }
 
+        // Callback interface the foreign shell implements to carry out effects
+        // requested by the core. The shell performs the real I/O and reports the
+        // outcome back with `RmpModel::resolve_effect(id, ..)`, quoting the same
+        // correlation `id` it was handed here.
+        #[::uniffi::export(callback_interface)]
+        pub trait RmpEffectHandler: Send + Sync + 'static {
+            fn handle_effect(&self, id: u64, effect: $Effect);
+        }
+
+        // Callback interface the shell implements to own long-lived streams. The
+        // framework calls `start_subscription`/`stop_subscription` as the model's
+        // subscription list changes; events the streams produce are fed back in
+        // via `RmpModel::action`.
+        #[::uniffi::export(callback_interface)]
+        pub trait RmpSubscriptionSink: Send + Sync + 'static {
+            fn start_subscription(&self, key: String, subscription: $Subscription);
+            fn stop_subscription(&self, key: String);
+        }
+
         // In test mode, just define the trait without the uniffi attributes
         // #[cfg(test)]
         // pub trait RmpViewModel: Send + Sync + 'static {
@@ -64,27 +179,130 @@ macro_rules! register_app {
                 std::sync::Arc::new(Self { data_dir })
             }
 
-            pub fn action(&self, action: $Action) {
-                // Get the global model and call its action method
-                let mut model = self
-                    .get_or_set_global_model()
-                    .write()
-                    .expect("Failed to acquire write lock on model");
+            // Call the action method from the RmpAppModel trait, collecting the
+            // effects it wants the shell to perform. The lock is dropped before
+            // the effects are dispatched so effect handlers (and any re-entrant
+            // `resolve_effect`) can take the lock again.
+            #[cfg(not(feature = "async"))]
+            pub fn action(&self, action: $Action) -> Result<(), $FfiErr> {
+                let effects = {
+                    let mut model = self
+                        .get_or_set_global_model()
+                        .write()
+                        .expect("Failed to acquire write lock on model");
+
+                    use $crate::traits::RmpAppModel;
+                    model.action(action)?
+                };
+
+                self.dispatch_effects(effects);
+                self.reconcile_subscriptions();
+                Ok(())
+            }
+
+            // Async counterpart: `await`-able from Swift/Kotlin via uniffi's
+            // `RustFuture`. The async mutex is released before dispatch, exactly
+            // as in the synchronous path.
+            #[cfg(feature = "async")]
+            pub async fn action(&self, action: $Action) -> Result<(), $FfiErr> {
+                let effects = {
+                    let mut model = self.get_or_set_global_model().lock().await;
+
+                    use $crate::traits::RmpAppModel;
+                    model.action_async(action).await?
+                };
 
-                // Call the action method from the RmpAppModel trait
+                self.dispatch_effects(effects);
+                self.reconcile_subscriptions();
+                Ok(())
+            }
+
+            /// Return a snapshot of the current state projected into the view
+            /// model.
+            ///
+            /// This is the pull-based companion to the push-based update
+            /// channel: a host renders initial state (or re-syncs after
+            /// backgrounding) by calling `view`, then keeps up with incremental
+            /// changes over `listen_for_model_updates`.
+            pub fn view(&self) -> $View {
                 use $crate::traits::RmpAppModel;
-                model.action(action);
+
+                #[cfg(not(feature = "async"))]
+                let model = self
+                    .get_or_set_global_model()
+                    .read()
+                    .expect("Failed to acquire read lock on model");
+
+                #[cfg(feature = "async")]
+                let model =
+                    $crate::futures::executor::block_on(self.get_or_set_global_model().lock());
+
+                model.view()
+            }
+
+            /// Register the shell's effect handler.
+            ///
+            /// Must be called once during start-up, before the first action is
+            /// dispatched; later calls are ignored.
+            pub fn register_effect_handler(&self, handler: Box<dyn RmpEffectHandler>) {
+                let _ = GLOBAL_EFFECT_HANDLER.set(handler);
+            }
+
+            /// Register the shell's subscription sink and start the model's
+            /// current subscriptions.
+            ///
+            /// Must be called once during start-up; later calls are ignored.
+            /// The sink is driven immediately with the model's current
+            /// subscription list, then after every subsequent action.
+            pub fn start_subscriptions(&self, sink: Box<dyn RmpSubscriptionSink>) {
+                let _ = GLOBAL_SUBSCRIPTION_SINK.set(sink);
+                self.reconcile_subscriptions();
+            }
+
+            /// Feed the result of a previously requested effect back into the
+            /// core as a follow-up action.
+            ///
+            /// The `id` is the correlation id the shell received in
+            /// `RmpEffectHandler::handle_effect`; it lets the shell match a
+            /// response to its request. The core re-enters through the normal
+            /// action path, so any further effects are dispatched in turn.
+            #[cfg(not(feature = "async"))]
+            pub fn resolve_effect(&self, id: u64, output: $Action) -> Result<(), $FfiErr> {
+                if !self.claim_pending_effect(id) {
+                    return Ok(());
+                }
+                self.action(output)
+            }
+
+            #[cfg(feature = "async")]
+            pub async fn resolve_effect(
+                &self,
+                id: u64,
+                output: $Action,
+            ) -> Result<(), $FfiErr> {
+                if !self.claim_pending_effect(id) {
+                    return Ok(());
+                }
+                self.action(output).await
             }
 
             pub fn listen_for_model_updates(&self, view_model: Box<dyn RmpViewModel>) {
                 // Set up the listener
+                #[cfg(not(feature = "async"))]
                 let model = self
                     .get_or_set_global_model()
                     .read()
                     .expect("Failed to acquire read lock on model");
 
-                // Just pass the updater as is
-                $crate::listen_for_model_updates(&*model, view_model);
+                // The async mutex has no blocking accessor, so block just long
+                // enough to clone the update receiver out of the model.
+                #[cfg(feature = "async")]
+                let model =
+                    $crate::futures::executor::block_on(self.get_or_set_global_model().lock());
+
+                // Just pass the updater as is. The error type cannot be inferred
+                // from the arguments, so name the model's trait parameter.
+                $crate::listen_for_model_updates::<_, _, $TraitErr>(&*model, view_model);
             }
         }
 
@@ -114,13 +332,112 @@ macro_rules! register_app {
 
         // 5. Helper methods for the FFI object
         impl RmpModel {
-            fn get_or_set_global_model(&self) -> &std::sync::RwLock<$Model> {
-                GLOBAL_MODEL.get_or_init(|| {
-                    // Create a new model
-                    let model =
-                        <$Model as $crate::traits::RmpAppModel>::create(self.data_dir.clone());
-                    std::sync::RwLock::new(model)
-                })
+            fn get_or_set_global_model(&self) -> &'static $crate::GlobalLock<$Model> {
+                if let Some(model) = $crate::get_global::<$Model>() {
+                    return model;
+                }
+                let model = <$Model as $crate::traits::RmpAppModel<$TraitErr>>::create(
+                    self.data_dir.clone(),
+                );
+                $crate::set_global::<$Model>(model)
+            }
+
+            fn pending_effects() -> &'static std::sync::Mutex<std::collections::HashSet<u64>> {
+                GLOBAL_PENDING_EFFECTS
+                    .get_or_init(|| std::sync::Mutex::new(std::collections::HashSet::new()))
+            }
+
+            // Remove `id` from the pending set, returning whether it was there.
+            // A response quoting an unknown or already-resolved id returns
+            // `false`, so `resolve_effect` can drop it.
+            fn claim_pending_effect(&self, id: u64) -> bool {
+                Self::pending_effects()
+                    .lock()
+                    .expect("pending effects poisoned")
+                    .remove(&id)
+            }
+
+            fn dispatch_effects(&self, effects: Vec<$Effect>) {
+                if let Some(handler) = GLOBAL_EFFECT_HANDLER.get() {
+                    for effect in effects {
+                        let id =
+                            EFFECT_ID.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                        Self::pending_effects()
+                            .lock()
+                            .expect("pending effects poisoned")
+                            .insert(id);
+                        handler.handle_effect(id, effect);
+                    }
+                }
+            }
+
+            fn reconcile_subscriptions(&self) {
+                let sink = match GLOBAL_SUBSCRIPTION_SINK.get() {
+                    Some(sink) => sink,
+                    None => return,
+                };
+
+                // Snapshot the desired subscriptions, releasing the model lock
+                // before touching the sink so stream callbacks can re-enter.
+                let desired = {
+                    use $crate::traits::RmpAppModel;
+
+                    #[cfg(not(feature = "async"))]
+                    let model = self
+                        .get_or_set_global_model()
+                        .read()
+                        .expect("Failed to acquire read lock on model");
+
+                    #[cfg(feature = "async")]
+                    let model = $crate::futures::executor::block_on(
+                        self.get_or_set_global_model().lock(),
+                    );
+
+                    model.subscriptions()
+                };
+
+                use $crate::traits::SubscriptionKey;
+
+                // Compute the start/stop plan and update the active-key set under
+                // the lock, then drop the guard *before* invoking the sink. A
+                // sink that synchronously emits an event re-enters
+                // `RmpModel::action` -> `reconcile_subscriptions` -> this lock,
+                // so holding the guard across the callbacks would deadlock the
+                // non-reentrant mutex.
+                let (to_start, to_stop): (Vec<(String, $Subscription)>, Vec<String>) = {
+                    let active = GLOBAL_ACTIVE_SUBSCRIPTIONS.get_or_init(|| {
+                        std::sync::Mutex::new(std::collections::HashSet::new())
+                    });
+                    let mut active = active.lock().expect("subscription set poisoned");
+
+                    let desired_keys: std::collections::HashSet<String> =
+                        desired.iter().map(|sub| sub.subscription_key()).collect();
+
+                    let to_stop: Vec<String> =
+                        active.difference(&desired_keys).cloned().collect();
+                    for key in &to_stop {
+                        active.remove(key);
+                    }
+
+                    let mut to_start = Vec::new();
+                    for sub in desired {
+                        let key = sub.subscription_key();
+                        if active.insert(key.clone()) {
+                            to_start.push((key, sub));
+                        }
+                    }
+
+                    (to_start, to_stop)
+                };
+
+                // Stop any stream that is no longer wanted, then start any
+                // newly-requested one.
+                for key in to_stop {
+                    sink.stop_subscription(key);
+                }
+                for (key, sub) in to_start {
+                    sink.start_subscription(key, sub);
+                }
             }
         }
 
@@ -15,6 +15,42 @@ mod tests {
     #[derive(Debug, PartialEq)]
     pub enum TestAction {
         TestAction,
+        // Requests the shell to perform some work; emitted as an effect.
+        DoWork,
+        // Always rejected, to exercise the fallible action path.
+        Reject,
+    }
+
+    // Define a test action error type. Real apps derive `uniffi::Error` here.
+    #[derive(Debug, PartialEq)]
+    pub enum TestActionError {
+        Rejected,
+    }
+
+    // Define a test effect type
+    #[derive(Debug, PartialEq)]
+    pub enum TestEffect {
+        Work { payload: i32 },
+    }
+
+    // Define the render-ready projection of the model
+    #[derive(Debug, PartialEq)]
+    pub struct TestView {
+        pub count: i32,
+    }
+
+    // Define a test subscription type
+    #[derive(Debug, PartialEq)]
+    pub enum TestSubscription {
+        Ticker,
+    }
+
+    impl crate::traits::SubscriptionKey for TestSubscription {
+        fn subscription_key(&self) -> String {
+            match self {
+                TestSubscription::Ticker => "ticker".to_string(),
+            }
+        }
     }
 
     // Define a test model with an app builder field for the update receiver
@@ -24,10 +60,14 @@ mod tests {
         pub data_dir: String,
     }
 
-    // Implement RmpAppModel for the test model
-    impl crate::traits::RmpAppModel for TestModel {
+    // Implement RmpAppModel for the test model. This model can fail, so it
+    // names its error type via the `ActionError` parameter.
+    impl crate::traits::RmpAppModel<TestActionError> for TestModel {
         type ActionType = TestAction;
         type UpdateType = TestModelUpdate;
+        type Effect = TestEffect;
+        type ViewModel = TestView;
+        type Subscription = TestSubscription;
 
         fn create(data_dir: String) -> Self {
             TestModel {
@@ -36,9 +76,30 @@ mod tests {
             }
         }
 
-        fn action(&mut self, action: Self::ActionType) {
+        fn action(
+            &mut self,
+            action: Self::ActionType,
+        ) -> Result<Vec<Self::Effect>, TestActionError> {
             match action {
-                TestAction::TestAction => self.count += 1,
+                TestAction::TestAction => {
+                    self.count += 1;
+                    Ok(vec![])
+                }
+                TestAction::DoWork => Ok(vec![TestEffect::Work { payload: self.count }]),
+                TestAction::Reject => Err(TestActionError::Rejected),
+            }
+        }
+
+        fn view(&self) -> Self::ViewModel {
+            TestView { count: self.count }
+        }
+
+        fn subscriptions(&self) -> Vec<Self::Subscription> {
+            // Run the ticker only once the model has counted up.
+            if self.count > 0 {
+                vec![TestSubscription::Ticker]
+            } else {
+                vec![]
             }
         }
     }
@@ -49,7 +110,16 @@ mod tests {
 
     // Use the register_app macro to generate the FFI code
     // This is what we're testing - that the macro expands properly
-    crate::register_app!(TestModel, TestViewModel, TestAction, TestModelUpdate);
+    crate::register_app!(
+        TestModel,
+        TestViewModel,
+        TestAction,
+        TestModelUpdate,
+        TestEffect,
+        TestView,
+        TestActionError,
+        TestSubscription
+    );
 
     #[test]
     fn test_model_creation() {
@@ -66,8 +136,8 @@ mod tests {
         let model = RmpModel::new("test_dir".to_string());
         
         // Call the action method
-        model.action(TestAction::TestAction);
-        
+        model.action(TestAction::TestAction).unwrap();
+
         // Get the global model
         let global_model = model.get_or_set_global_model().read().unwrap();
         
@@ -75,6 +145,98 @@ mod tests {
         assert_eq!(global_model.count, 1);
     }
 
+    // A model whose actions never fail. Its impl header omits the `ActionError`
+    // parameter, relying on the `NoActionError` default; only `action`'s return
+    // type names it.
+    #[derive(Debug)]
+    struct InfallibleModel {
+        count: i32,
+    }
+
+    impl crate::traits::RmpAppModel for InfallibleModel {
+        type ActionType = TestAction;
+        type UpdateType = TestModelUpdate;
+        type Effect = TestEffect;
+        type ViewModel = TestView;
+        type Subscription = crate::traits::NoSubscription;
+
+        fn create(_data_dir: String) -> Self {
+            InfallibleModel { count: 0 }
+        }
+
+        fn action(
+            &mut self,
+            _action: Self::ActionType,
+        ) -> Result<Vec<Self::Effect>, crate::traits::NoActionError> {
+            self.count += 1;
+            Ok(vec![])
+        }
+
+        fn view(&self) -> Self::ViewModel {
+            TestView { count: self.count }
+        }
+    }
+
+    #[test]
+    fn test_infallible_model_omits_action_error() {
+        use crate::traits::RmpAppModel;
+
+        let mut model = InfallibleModel::create("test_dir".to_string());
+        assert_eq!(model.action(TestAction::TestAction), Ok(vec![]));
+        assert_eq!(model.count, 1);
+    }
+
+    #[test]
+    fn test_action_emits_effects() {
+        use crate::traits::RmpAppModel;
+
+        let mut model = TestModel::create("test_dir".to_string());
+
+        // A plain action mutates the model and emits no effects.
+        assert_eq!(model.action(TestAction::TestAction), Ok(vec![]));
+        assert_eq!(model.count, 1);
+
+        // An action that needs work emits a typed effect instead of doing I/O.
+        assert_eq!(
+            model.action(TestAction::DoWork),
+            Ok(vec![TestEffect::Work { payload: 1 }])
+        );
+
+        // A rejected action surfaces a typed error.
+        assert_eq!(model.action(TestAction::Reject), Err(TestActionError::Rejected));
+    }
+
+    #[test]
+    fn test_view_projection() {
+        use crate::traits::RmpAppModel;
+
+        // Drive a local model rather than the process-global one, which the
+        // other tests share and mutate concurrently.
+        let mut model = TestModel::create("test_dir".to_string());
+
+        // A fresh model projects its initial state.
+        assert_eq!(model.view(), TestView { count: 0 });
+
+        // After an action the snapshot reflects the new state.
+        model.action(TestAction::TestAction).unwrap();
+        assert_eq!(model.view(), TestView { count: 1 });
+    }
+
+    #[test]
+    fn test_subscriptions() {
+        use crate::traits::{RmpAppModel, SubscriptionKey};
+
+        let mut model = TestModel::create("test_dir".to_string());
+
+        // No subscriptions until the model has counted up.
+        assert!(model.subscriptions().is_empty());
+
+        model.action(TestAction::TestAction).unwrap();
+        let subs = model.subscriptions();
+        assert_eq!(subs, vec![TestSubscription::Ticker]);
+        assert_eq!(subs[0].subscription_key(), "ticker");
+    }
+
     #[test]
     fn test_view_model() {
         // Create a channel for the view model
@@ -19,17 +19,86 @@ pub trait RmpViewModel: Send + Sync + 'static {
     fn model_update(&self, model_update: Self::UpdateType);
 }
 
+/// A subscription's stable identity
+///
+/// The framework diffs the subscription list returned after each action by key,
+/// so a key must stay the same across actions for as long as the stream should
+/// keep running and differ between distinct streams.
+pub trait SubscriptionKey {
+    /// Return the stable key identifying this subscription.
+    fn subscription_key(&self) -> String;
+}
+
+/// A subscription type for models that declare no subscriptions
+///
+/// Uninhabited, so `subscriptions()` can only ever return an empty list. Use it
+/// as the `Subscription` associated type when a model has no ongoing streams.
+#[derive(Debug)]
+pub enum NoSubscription {}
+
+impl SubscriptionKey for NoSubscription {
+    fn subscription_key(&self) -> String {
+        match *self {}
+    }
+}
+
+/// The default action error for models that never reject an action
+///
+/// It is the default for [`RmpAppModel`]'s `ActionError` parameter, so an
+/// infallible model can implement the trait without naming an error type in
+/// the impl header; only `action`'s return type names it. It deliberately does *not* derive [`uniffi::Error`]: that derive needs
+/// the scaffolding emitted by `setup_scaffolding!()`, which only runs in the
+/// consumer crate. `register_app!` instead generates its own uninhabited FFI
+/// error there and bridges this type into it, so the fallible signature still
+/// lowers while the thrown case can never actually occur.
+#[derive(Debug, PartialEq)]
+pub enum NoActionError {}
+
 /// Trait for application models that can be managed by the framework
 ///
 /// By implementing this trait, a model can be initialized and managed by the framework,
 /// with automatic integration into the FFI layer.
-pub trait RmpAppModel {
+///
+/// The `ActionError` type parameter is the error an action may reject with. It
+/// defaults to [`NoActionError`], so an infallible model implements the trait
+/// as `impl RmpAppModel for MyModel`, naming [`NoActionError`] only in the
+/// return type of `action`; a model
+/// that can fail implements `impl RmpAppModel<MyError> for MyModel`, where
+/// `MyError` derives [`uniffi::Error`] so uniffi lowers a rejected action into a
+/// thrown exception on Swift/Kotlin.
+pub trait RmpAppModel<ActionError = NoActionError>
+where
+    ActionError: std::fmt::Debug,
+{
     /// The type of actions that can be dispatched to the model
     type ActionType: std::fmt::Debug;
 
     /// The type of updates that can be sent from the model to the view
     type UpdateType: std::fmt::Debug;
 
+    /// The type of side effects the model can request the shell to perform
+    ///
+    /// Handling an action never performs I/O itself; instead it returns the
+    /// effects it wants carried out. The framework hands each effect to the
+    /// foreign shell, which owns all real network/disk/timer work and feeds the
+    /// result back in as a follow-up action. This keeps the core deterministic
+    /// and unit-testable.
+    type Effect: std::fmt::Debug;
+
+    /// The render-ready projection of the model handed to the view
+    ///
+    /// Following the Model/ViewModel split, the model keeps its internal state
+    /// private and exposes a snapshot the UI can render directly via
+    /// [`view`](Self::view).
+    type ViewModel: std::fmt::Debug;
+
+    /// The ongoing streams the model wants the shell to keep running
+    ///
+    /// Unlike one-shot effects, subscriptions describe long-lived data sources
+    /// — periodic ticks, websocket feeds — that the shell owns. Set this to
+    /// [`NoSubscription`] when the model has no such streams.
+    type Subscription: std::fmt::Debug + SubscriptionKey;
+
     /// Create a new instance of the model
     ///
     /// This function is called by the framework to initialize the model.
@@ -39,7 +108,46 @@ pub trait RmpAppModel {
     /// Handle an action dispatched to the model
     ///
     /// This function is called when an action is dispatched from the frontend.
-    fn action(&mut self, action: Self::ActionType);
+    /// It mutates the model synchronously and, on success, returns the effects
+    /// the shell should perform on the core's behalf (an empty vector when
+    /// there is no I/O to request). Returning `Err` rejects the action — e.g.
+    /// on a validation or auth failure — which the platform surfaces as a
+    /// thrown exception.
+    fn action(&mut self, action: Self::ActionType)
+        -> Result<Vec<Self::Effect>, ActionError>;
+
+    /// Handle an action asynchronously
+    ///
+    /// This is the `await`-able counterpart to [`action`](Self::action), used
+    /// by the exported async method when the `async` feature is enabled. The
+    /// default simply defers to the synchronous handler, so models only
+    /// override it when they genuinely need to suspend while computing their
+    /// effects (the real I/O still happens in the shell).
+    #[cfg(feature = "async")]
+    #[allow(async_fn_in_trait)]
+    async fn action_async(
+        &mut self,
+        action: Self::ActionType,
+    ) -> Result<Vec<Self::Effect>, ActionError> {
+        self.action(action)
+    }
+
+    /// Project the current model into its render-ready view model
+    ///
+    /// Hosts call this to obtain the current state on demand — to render a
+    /// freshly-attached UI or to re-sync after backgrounding — without waiting
+    /// for the next incremental update on the channel.
+    fn view(&self) -> Self::ViewModel;
+
+    /// Declare the streams that should currently be running
+    ///
+    /// The framework calls this after each action and diffs the result against
+    /// the previously-active set, asking the shell to start or stop streams by
+    /// key. Events produced by those streams come back in as ordinary actions.
+    /// The default declares none.
+    fn subscriptions(&self) -> Vec<Self::Subscription> {
+        Vec::new()
+    }
 
     /// Get access to the model update receiver
     ///
@@ -79,16 +187,30 @@ impl<T, U> AppBuilder<T, U> {
 ///
 /// This trait is implemented by models that contain an AppBuilder field,
 /// providing access to the update receiver.
-pub trait BuildableApp<U>: RmpAppModel<UpdateType = U> + Sized {
+pub trait BuildableApp<U, ActionError = NoActionError>:
+    RmpAppModel<ActionError, UpdateType = U> + Sized
+where
+    ActionError: std::fmt::Debug,
+{
     /// Get the AppBuilder from the model
     fn builder(&self) -> &AppBuilder<Self, U>;
 }
 
-// Helper macro to implement BuildableApp for a model
+// Helper macro to implement BuildableApp for a model. Fallible models name
+// their error type as a fourth argument; infallible ones omit it and fall back
+// to `NoActionError`, mirroring `RmpAppModel`.
 #[macro_export]
 macro_rules! impl_buildable_app {
     ($Model:ty, $UpdateType:ty, $builder_field:ident) => {
-        impl $crate::traits::BuildableApp<$UpdateType> for $Model {
+        $crate::impl_buildable_app!(
+            $Model,
+            $UpdateType,
+            $builder_field,
+            $crate::traits::NoActionError
+        );
+    };
+    ($Model:ty, $UpdateType:ty, $builder_field:ident, $ActionError:ty) => {
+        impl $crate::traits::BuildableApp<$UpdateType, $ActionError> for $Model {
             fn builder(&self) -> &$crate::traits::AppBuilder<Self, $UpdateType> {
                 &self.$builder_field
             }
@@ -8,9 +8,10 @@ use std::thread;
 ///
 /// This function creates a thread that listens for model updates and forwards them to the view model.
 /// It's used by the framework's generated code to handle the boilerplate of setting up the listener.
-pub fn listen_for_model_updates<M, V>(model: &M, view_model: Box<V>)
+pub fn listen_for_model_updates<M, V, E>(model: &M, view_model: Box<V>)
 where
-    M: RmpAppModel,
+    M: RmpAppModel<E>,
+    E: std::fmt::Debug,
     V: RmpViewModel<UpdateType = M::UpdateType> + ?Sized + 'static,
     M::UpdateType: Send + 'static,
 {
@@ -36,9 +37,10 @@ pub fn create_model_update_channel<T>() -> (Sender<T>, Receiver<T>) {
 /// Create a new app builder with a receiver for model updates
 ///
 /// This is a convenience function to create an AppBuilder with a new receiver.
-pub fn create_app_builder<M, U>(data_dir: String, receiver: Receiver<U>) -> AppBuilder<M, U>
+pub fn create_app_builder<M, U, E>(data_dir: String, receiver: Receiver<U>) -> AppBuilder<M, U>
 where
-    M: RmpAppModel<UpdateType = U>,
+    M: RmpAppModel<E, UpdateType = U>,
+    E: std::fmt::Debug,
 {
     AppBuilder::new(data_dir, receiver)
 }